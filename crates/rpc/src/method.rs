@@ -11,3 +11,6 @@ pub use chain_id::chain_id;
 pub use get_block_transaction_count::get_block_transaction_count;
 pub use get_nonce::get_nonce;
 pub use syncing::syncing;
+
+// The fee-estimation methods live with the versioned RPC types they depend on.
+pub use crate::v05::method::estimate_fee::{estimate_fee, estimate_fee_per_transaction};
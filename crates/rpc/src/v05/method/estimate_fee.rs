@@ -1,7 +1,6 @@
 use anyhow::Context;
-use pathfinder_common::BlockId;
+use pathfinder_common::{BlockHeader, BlockId, GasPrice, L1DataAvailabilityMode};
 use pathfinder_executor::{ExecutionState, L1BlobDataAvailability};
-use serde_with::serde_as;
 
 use crate::context::RpcContext;
 use crate::error::ApplicationError;
@@ -11,7 +10,28 @@ use crate::v02::types::request::BroadcastedTransaction;
 #[serde(deny_unknown_fields)]
 pub struct EstimateFeeInput {
     pub request: Vec<BroadcastedTransaction>,
+    #[serde(default)]
+    pub simulation_flags: Vec<SimulationFlag>,
     pub block_id: BlockId,
+    /// Estimate against the *projected* next-block gas prices rather than the
+    /// ones recorded in the target block header, so an estimate for a
+    /// transaction the caller intends to submit doesn't immediately go stale.
+    #[serde(default)]
+    pub forecast: bool,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+pub enum SimulationFlag {
+    /// Skip the account's `__validate__` entry point, so fees can be estimated
+    /// for transactions whose signatures aren't available yet (wallet flows).
+    #[serde(rename = "SKIP_VALIDATE")]
+    SkipValidate,
+    /// Skip transferring the fee to the sequencer. `estimate_fee` never charges
+    /// the fee in the first place — the balance transfer is always elided so the
+    /// sender needn't have the funds on hand — so for this method the flag is a
+    /// documented no-op, accepted for spec parity with `simulate_transactions`.
+    #[serde(rename = "SKIP_FEE_CHARGE")]
+    SkipFeeCharge,
 }
 
 #[derive(Debug)]
@@ -72,25 +92,210 @@ impl From<EstimateFeeError> for ApplicationError {
     }
 }
 
-#[serde_as]
-#[derive(Clone, Debug, serde::Serialize, PartialEq, Eq)]
-pub struct FeeEstimate {
-    #[serde_as(as = "pathfinder_serde::U256AsHexStr")]
-    pub gas_consumed: primitive_types::U256,
-    #[serde_as(as = "pathfinder_serde::U256AsHexStr")]
-    pub gas_price: primitive_types::U256,
-    #[serde_as(as = "pathfinder_serde::U256AsHexStr")]
-    pub overall_fee: primitive_types::U256,
+pub use pathfinder_executor::types::FeeEstimate;
+
+/// Maps a block's data-availability mode to the blob-data-gas setting the
+/// executor should estimate under, so estimates on blob-posting chains reflect
+/// real EIP-4844-style data-gas costs instead of always assuming blobs are off.
+fn l1_blob_data_availability(mode: L1DataAvailabilityMode) -> L1BlobDataAvailability {
+    match mode {
+        L1DataAvailabilityMode::Blob => L1BlobDataAvailability::Enabled,
+        L1DataAvailabilityMode::Calldata => L1BlobDataAvailability::Disabled,
+    }
 }
 
-impl From<pathfinder_executor::types::FeeEstimate> for FeeEstimate {
-    fn from(value: pathfinder_executor::types::FeeEstimate) -> Self {
-        Self {
-            gas_consumed: value.gas_consumed,
-            gas_price: value.gas_price,
-            overall_fee: value.overall_fee,
+/// Number of recent block headers fed into the forecast gas-price projection.
+const FORECAST_WINDOW: u64 = 10;
+/// EIP-1559 elasticity multiplier relating a block's gas target to its limit.
+const ELASTICITY: u128 = 2;
+/// EIP-1559's cap on how fast the base fee may move between two blocks.
+const BASE_FEE_MAX_CHANGE_DENOMINATOR: u128 = 8;
+
+/// EIP-1559 base-fee recurrence for a single gas dimension: projects the next
+/// block's price from the parent block's price and utilization.
+///
+/// With `target = parent_gas_limit / ELASTICITY`, the price rises when the
+/// parent consumed more gas than the target and falls when it consumed less, by
+/// at most `1 / BASE_FEE_MAX_CHANGE_DENOMINATOR` of the parent price per block.
+/// The result is clamped to a non-negative minimum (implicit in `u128`).
+fn next_base_fee(parent_price: u128, parent_gas_used: u128, parent_gas_limit: u128) -> u128 {
+    let target = parent_gas_limit / ELASTICITY;
+    if target == 0 {
+        return parent_price;
+    }
+    // A block cannot use more than its limit; this keeps the rise within the cap.
+    let parent_gas_used = parent_gas_used.min(parent_gas_limit);
+
+    if parent_gas_used >= target {
+        let delta = parent_price.saturating_mul(parent_gas_used - target)
+            / target
+            / BASE_FEE_MAX_CHANGE_DENOMINATOR;
+        parent_price.saturating_add(delta)
+    } else {
+        let delta = parent_price.saturating_mul(target - parent_gas_used)
+            / target
+            / BASE_FEE_MAX_CHANGE_DENOMINATOR;
+        parent_price.saturating_sub(delta)
+    }
+}
+
+/// Projects the next block's base fee for a single gas dimension from the
+/// *parent* block's `parent_price` and `parent_used` utilization, taking the
+/// mean utilization across the recent `window` as the EIP-1559 target.
+///
+/// Starknet headers carry no explicit `(gas_used, gas_limit)` pair, so a block's
+/// transaction count stands in for its utilization and the window mean stands in
+/// for the chain's equilibrium fullness. A parent busier than that mean raises
+/// the next fee, a quieter one lowers it, and one exactly at the mean leaves it
+/// unchanged — so the projection moves in both directions.
+fn forecast_price(parent_price: u128, parent_used: u128, window: &[u128]) -> u128 {
+    if window.is_empty() {
+        return parent_price;
+    }
+    // Compare `parent_used * len` against the window sum rather than
+    // `parent_used` against a truncated `sum / len`, so the mean is exact.
+    let len = window.len() as u128;
+    let sum = window.iter().sum::<u128>();
+    next_base_fee(
+        parent_price,
+        parent_used.saturating_mul(len),
+        sum.saturating_mul(ELASTICITY),
+    )
+}
+
+/// Replaces the gas prices recorded in `header` with the prices projected for
+/// the next block via the EIP-1559 [`next_base_fee`] recurrence.
+///
+/// The next block's base fee is a function of its parent alone, so the
+/// projection anchors on `header` — the parent of the block being forecast —
+/// and uses its utilization against the mean utilization of up to
+/// [`FORECAST_WINDOW`] recent blocks (see [`forecast_price`]).
+fn forecast_gas_prices(
+    db: &pathfinder_storage::Transaction<'_>,
+    mut header: BlockHeader,
+) -> anyhow::Result<BlockHeader> {
+    let latest = header.number;
+    let oldest = latest.get().saturating_sub(FORECAST_WINDOW - 1);
+
+    // Recent block fullness, including the parent (`header`) itself, used to
+    // estimate the chain's equilibrium utilization.
+    let mut window = vec![header.transaction_count as u128];
+    for number in (oldest..latest.get()).rev() {
+        let number = pathfinder_common::BlockNumber::new_or_panic(number);
+        match db
+            .block_header(number.into())
+            .context("Querying block header for fee forecast")?
+        {
+            Some(h) => window.push(h.transaction_count as u128),
+            None => break,
         }
     }
+
+    let parent_used = header.transaction_count as u128;
+    let project = |price: u128| GasPrice(forecast_price(price, parent_used, &window));
+
+    header.eth_l1_gas_price = project(header.eth_l1_gas_price.0);
+    header.strk_l1_gas_price = project(header.strk_l1_gas_price.0);
+    header.eth_l1_data_gas_price = project(header.eth_l1_data_gas_price.0);
+    header.strk_l1_data_gas_price = project(header.strk_l1_data_gas_price.0);
+
+    Ok(header)
+}
+
+/// The pending state update carried alongside the pending block header, as
+/// [`ExecutionState::simulation`] expects it.
+type PendingStateUpdate = std::sync::Arc<pathfinder_common::StateUpdate>;
+
+/// The resolved inputs shared by both estimate entry points: the (optionally
+/// forecast) block header, the pending state update, the DA mode, the
+/// `skip_validate` flag and the mapped transactions.
+struct PreparedEstimate {
+    header: BlockHeader,
+    pending: Option<PendingStateUpdate>,
+    l1_blob_data_availability: L1BlobDataAvailability,
+    skip_validate: bool,
+    transactions: Vec<pathfinder_executor::Transaction>,
+}
+
+impl PreparedEstimate {
+    /// Builds a fresh [`ExecutionState`]. `ExecutionState::simulation` borrows
+    /// the connection and is consumed by `estimate`, so callers that estimate
+    /// more than once (e.g. the non-fatal batch) call this per attempt.
+    fn execution_state<'db>(
+        &self,
+        db: &'db pathfinder_storage::Transaction<'db>,
+        context: &RpcContext,
+    ) -> ExecutionState<'db> {
+        ExecutionState::simulation(
+            db,
+            context.chain_id,
+            self.header.clone(),
+            self.pending.clone(),
+            self.l1_blob_data_availability,
+            context.config.custom_versioned_constants.clone(),
+        )
+    }
+}
+
+/// Resolves the target block header (applying the forecast projection when
+/// requested), the DA mode, the simulation flags and the broadcasted
+/// transactions into the form the executor consumes.
+fn prepare_estimate(
+    context: &RpcContext,
+    db: &pathfinder_storage::Transaction<'_>,
+    input: EstimateFeeInput,
+) -> Result<PreparedEstimate, EstimateFeeError> {
+    let (header, pending) = match input.block_id {
+        BlockId::Pending => {
+            let pending = context
+                .pending_data
+                .get(db)
+                .context("Querying pending data")?;
+
+            (pending.header(), Some(pending.state_update.clone()))
+        }
+        other => {
+            let block_id = other.try_into().expect("Only pending cast should fail");
+            let header = db
+                .block_header(block_id)
+                .context("Querying block header")?
+                .ok_or(EstimateFeeError::BlockNotFound)?;
+
+            (header, None)
+        }
+    };
+
+    // Optionally project the gas prices of the next block so the estimate stays
+    // valid for a transaction the caller is about to submit.
+    let header = if input.forecast {
+        forecast_gas_prices(db, header)?
+    } else {
+        header
+    };
+
+    let l1_blob_data_availability = l1_blob_data_availability(header.l1_da_mode);
+
+    let skip_validate = input
+        .simulation_flags
+        .contains(&SimulationFlag::SkipValidate);
+    // `SKIP_FEE_CHARGE` needs no threading: fee estimation already runs without
+    // charging the sender (the executor's `estimate` elides the fee transfer
+    // unconditionally), so the flag is a documented no-op here and only
+    // `skip_validate` changes the resulting estimate.
+
+    let transactions = input
+        .request
+        .into_iter()
+        .map(|tx| crate::executor::map_broadcasted_transaction(&tx, context.chain_id))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(PreparedEstimate {
+        header,
+        pending,
+        l1_blob_data_availability,
+        skip_validate,
+        transactions,
+    })
 }
 
 pub async fn estimate_fee(
@@ -107,45 +312,12 @@ pub async fn estimate_fee(
             .context("Creating database connection")?;
         let db = db.transaction().context("Creating database transaction")?;
 
-        let (header, pending) = match input.block_id {
-            BlockId::Pending => {
-                let pending = context
-                    .pending_data
-                    .get(&db)
-                    .context("Querying pending data")?;
-
-                (pending.header(), Some(pending.state_update.clone()))
-            }
-            other => {
-                let block_id = other.try_into().expect("Only pending cast should fail");
-                let header = db
-                    .block_header(block_id)
-                    .context("Querying block header")?
-                    .ok_or(EstimateFeeError::BlockNotFound)?;
-
-                (header, None)
-            }
-        };
-
-        let state = ExecutionState::simulation(
-            &db,
-            context.chain_id,
-            header,
-            pending,
-            L1BlobDataAvailability::Disabled,
-            context.config.custom_versioned_constants,
-        );
-
-        let transactions = input
-            .request
-            .into_iter()
-            .map(|tx| crate::executor::map_broadcasted_transaction(&tx, context.chain_id))
-            .collect::<Result<Vec<_>, _>>()?;
+        let prepared = prepare_estimate(&context, &db, input)?;
 
         let result = pathfinder_executor::estimate(
-            state,
-            transactions,
-            false,
+            prepared.execution_state(&db, &context),
+            prepared.transactions,
+            prepared.skip_validate,
             // skip nonce check because it is not necessary for fee estimation
             true,
         )?;
@@ -155,7 +327,79 @@ pub async fn estimate_fee(
     .await
     .context("Executing transaction")??;
 
-    Ok(result.into_iter().map(Into::into).collect())
+    Ok(result)
+}
+
+/// A single transaction's reverting execution within a non-fatal batch
+/// estimate, carrying the offending `transaction_index` and its revert reason.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionFailure {
+    pub transaction_index: usize,
+    pub revert_error: String,
+}
+
+/// Like [`estimate_fee`], but a reverting transaction does not collapse the
+/// whole batch: the returned vector carries the estimates of the transactions
+/// that executed successfully and an [`ExecutionFailure`] for the one that
+/// reverted. Because later transactions in a batch generally depend on the
+/// state produced by the earlier ones, the batch stops at the first failure and
+/// the transactions after it get no entry — callers simulating a dependent
+/// sequence see exactly which step fails and what the preceding steps cost.
+pub async fn estimate_fee_per_transaction(
+    context: RpcContext,
+    input: EstimateFeeInput,
+) -> Result<Vec<Result<FeeEstimate, ExecutionFailure>>, EstimateFeeError> {
+    use pathfinder_executor::TransactionExecutionError;
+
+    let span = tracing::Span::current();
+
+    let result = tokio::task::spawn_blocking(move || {
+        let _g = span.enter();
+        let mut db = context
+            .execution_storage
+            .connection()
+            .context("Creating database connection")?;
+        let db = db.transaction().context("Creating database transaction")?;
+
+        let prepared = prepare_estimate(&context, &db, input)?;
+        let skip_validate = prepared.skip_validate;
+
+        match pathfinder_executor::estimate(
+            prepared.execution_state(&db, &context),
+            prepared.transactions.clone(),
+            skip_validate,
+            true,
+        ) {
+            Ok(estimates) => Ok(estimates.into_iter().map(Ok).collect()),
+            Err(TransactionExecutionError::ExecutionError {
+                transaction_index,
+                error,
+            }) => {
+                // Re-run only the transactions preceding the failing one to
+                // recover their costs, then append the failure.
+                let preceding = pathfinder_executor::estimate(
+                    prepared.execution_state(&db, &context),
+                    prepared.transactions[..transaction_index].to_vec(),
+                    skip_validate,
+                    true,
+                )?;
+
+                let mut results: Vec<Result<FeeEstimate, ExecutionFailure>> =
+                    preceding.into_iter().map(Ok).collect();
+                results.push(Err(ExecutionFailure {
+                    transaction_index,
+                    revert_error: error.to_string(),
+                }));
+
+                Ok(results)
+            }
+            Err(other) => Err(other.into()),
+        }
+    })
+    .await
+    .context("Executing transaction")??;
+
+    Ok(result)
 }
 
 #[cfg(test)]
@@ -174,6 +418,100 @@ pub(crate) mod tests {
     use super::*;
     use crate::v02::types::request::BroadcastedInvokeTransaction;
 
+    mod data_availability {
+        use pathfinder_common::{BlockHeader, L1DataAvailabilityMode};
+        use pathfinder_executor::L1BlobDataAvailability;
+
+        use super::super::l1_blob_data_availability;
+
+        #[test]
+        fn blob_mode_enables_data_gas_estimation() {
+            assert_eq!(
+                l1_blob_data_availability(L1DataAvailabilityMode::Blob),
+                L1BlobDataAvailability::Enabled
+            );
+        }
+
+        #[test]
+        fn blob_mode_header_is_estimated_with_blobs_enabled() {
+            // Drive the branch the way `prepare_estimate` does — off a header's
+            // `l1_da_mode` — since the Calldata-only test network never exercises
+            // the Blob path end-to-end.
+            let header = BlockHeader {
+                l1_da_mode: L1DataAvailabilityMode::Blob,
+                ..Default::default()
+            };
+            assert_eq!(
+                l1_blob_data_availability(header.l1_da_mode),
+                L1BlobDataAvailability::Enabled
+            );
+        }
+
+        #[test]
+        fn calldata_mode_disables_data_gas_estimation() {
+            assert_eq!(
+                l1_blob_data_availability(L1DataAvailabilityMode::Calldata),
+                L1BlobDataAvailability::Disabled
+            );
+        }
+    }
+
+    mod forecast {
+        use super::super::{forecast_price, next_base_fee};
+
+        // Apart from the degenerate case, the `next_base_fee` cases use a gas
+        // limit of 100, so `target = 50`.
+
+        #[test]
+        fn price_is_unchanged_at_the_gas_target() {
+            assert_eq!(next_base_fee(1000, 50, 100), 1000);
+        }
+
+        #[test]
+        fn price_rises_above_the_gas_target() {
+            // delta = 1000 * (100 - 50) / 50 / 8 = 125.
+            assert_eq!(next_base_fee(1000, 100, 100), 1125);
+        }
+
+        #[test]
+        fn price_falls_below_the_gas_target() {
+            // delta = 1000 * (50 - 0) / 50 / 8 = 125.
+            assert_eq!(next_base_fee(1000, 0, 100), 875);
+        }
+
+        #[test]
+        fn degenerate_gas_limit_leaves_the_price_untouched() {
+            assert_eq!(next_base_fee(1000, 7, 0), 1000);
+        }
+
+        // `forecast_price` takes the window mean as the target, so the direction
+        // depends on the parent's fullness relative to that mean.
+
+        #[test]
+        fn forecast_rises_when_parent_is_busier_than_the_window_mean() {
+            // mean = (1 + 1 + 4) / 3 = 2; parent 4 > 2 so the fee rises.
+            // delta = 1000 * (4 - 2) / 2 / 8 = 125.
+            assert_eq!(forecast_price(1000, 4, &[1, 1, 4]), 1125);
+        }
+
+        #[test]
+        fn forecast_falls_when_parent_is_quieter_than_the_window_mean() {
+            // mean = (4 + 4 + 1) / 3 = 3; parent 1 < 3 so the fee falls.
+            // delta = 1000 * (3 - 1) / 3 / 8 = 83.
+            assert_eq!(forecast_price(1000, 1, &[4, 4, 1]), 917);
+        }
+
+        #[test]
+        fn forecast_holds_when_parent_matches_the_window_mean() {
+            assert_eq!(forecast_price(1000, 2, &[2, 2, 2]), 1000);
+        }
+
+        #[test]
+        fn forecast_on_an_empty_window_is_a_no_op() {
+            assert_eq!(forecast_price(1000, 5, &[]), 1000);
+        }
+    }
+
     mod parsing {
         use serde_json::json;
 
@@ -210,13 +548,16 @@ pub(crate) mod tests {
                         ]
                     }
                 ],
+                ["SKIP_VALIDATE"],
                 { "block_hash": "0xabcde" }
             ]);
 
             let input = serde_json::from_value::<EstimateFeeInput>(positional).unwrap();
             let expected = EstimateFeeInput {
                 request: vec![test_invoke_txn()],
+                simulation_flags: vec![SimulationFlag::SkipValidate],
                 block_id: BlockId::Hash(BlockHash(felt!("0xabcde"))),
+                forecast: false,
             };
             assert_eq!(input, expected);
         }
@@ -239,15 +580,32 @@ pub(crate) mod tests {
                         ]
                     }
                 ],
+                "simulation_flags": ["SKIP_VALIDATE"],
                 "block_id": { "block_hash": "0xabcde" }
             });
             let input = serde_json::from_value::<EstimateFeeInput>(named_args).unwrap();
             let expected = EstimateFeeInput {
                 request: vec![test_invoke_txn()],
+                simulation_flags: vec![SimulationFlag::SkipValidate],
                 block_id: BlockId::Hash(BlockHash(felt!("0xabcde"))),
+                forecast: false,
             };
             assert_eq!(input, expected);
         }
+
+        #[test]
+        fn both_simulation_flags() {
+            let named_args = json!({
+                "request": [],
+                "simulation_flags": ["SKIP_VALIDATE", "SKIP_FEE_CHARGE"],
+                "block_id": { "block_hash": "0xabcde" }
+            });
+            let input = serde_json::from_value::<EstimateFeeInput>(named_args).unwrap();
+            assert_eq!(
+                input.simulation_flags,
+                vec![SimulationFlag::SkipValidate, SimulationFlag::SkipFeeCharge]
+            );
+        }
     }
 
     mod in_memory {
@@ -264,8 +622,33 @@ pub(crate) mod tests {
         };
         use crate::v02::types::{ContractClass, SierraContractClass};
 
-        #[test_log::test(tokio::test)]
-        async fn declare_deploy_and_invoke_sierra_class() {
+        /// Builds the full per-resource breakdown for the test network, whose
+        /// L1 gas price is `1` and which neither consumes L2 gas nor posts blob
+        /// data, so only the L1 gas dimension is non-zero.
+        fn fee_estimate(gas_consumed: u64, overall_fee: u64) -> FeeEstimate {
+            use pathfinder_executor::types::PriceUnit;
+            FeeEstimate {
+                l1_gas_consumed: gas_consumed.into(),
+                l1_gas_price: 1.into(),
+                l1_data_gas_consumed: 0.into(),
+                l1_data_gas_price: 0.into(),
+                l2_gas_consumed: 0.into(),
+                l2_gas_price: 0.into(),
+                overall_fee: overall_fee.into(),
+                unit: PriceUnit::Wei,
+            }
+        }
+
+        /// A declare + universal-deployer deploy + two invokes of the deployed
+        /// Sierra class, returning the context, the block to estimate against,
+        /// the account address and the batch of transactions. Shared by the
+        /// estimate tests.
+        async fn declare_deploy_invoke_batch() -> (
+            crate::context::RpcContext,
+            pathfinder_common::BlockHeader,
+            ContractAddress,
+            Vec<BroadcastedTransaction>,
+        ) {
             let (context, last_block_header, account_contract_address, universal_deployer_address) =
                 crate::test_setup::test_context().await;
 
@@ -362,36 +745,37 @@ pub(crate) mod tests {
                 }),
             );
 
+            let requests = vec![
+                declare_transaction,
+                deploy_transaction,
+                invoke_transaction,
+                invoke_v0_transaction,
+            ];
+
+            (
+                context,
+                last_block_header,
+                account_contract_address,
+                requests,
+            )
+        }
+
+        #[test_log::test(tokio::test)]
+        async fn declare_deploy_and_invoke_sierra_class() {
+            let (context, last_block_header, _account, requests) =
+                declare_deploy_invoke_batch().await;
+
             let input = EstimateFeeInput {
-                request: vec![
-                    declare_transaction,
-                    deploy_transaction,
-                    invoke_transaction,
-                    invoke_v0_transaction,
-                ],
+                request: requests,
+                simulation_flags: vec![],
                 block_id: BlockId::Number(last_block_header.number),
+                forecast: false,
             };
             let result = estimate_fee(context, input).await.unwrap();
-            let declare_expected = FeeEstimate {
-                gas_consumed: 2768.into(),
-                gas_price: 1.into(),
-                overall_fee: 2768.into(),
-            };
-            let deploy_expected = FeeEstimate {
-                gas_consumed: 3020.into(),
-                gas_price: 1.into(),
-                overall_fee: 3020.into(),
-            };
-            let invoke_expected = FeeEstimate {
-                gas_consumed: 1674.into(),
-                gas_price: 1.into(),
-                overall_fee: 1674.into(),
-            };
-            let invoke_v0_expected = FeeEstimate {
-                gas_consumed: 1669.into(),
-                gas_price: 1.into(),
-                overall_fee: 1669.into(),
-            };
+            let declare_expected = fee_estimate(2768, 2768);
+            let deploy_expected = fee_estimate(3020, 3020);
+            let invoke_expected = fee_estimate(1674, 1674);
+            let invoke_v0_expected = fee_estimate(1669, 1669);
             assert_eq!(
                 result,
                 vec![
@@ -402,5 +786,220 @@ pub(crate) mod tests {
                 ]
             );
         }
+
+        #[test_log::test(tokio::test)]
+        async fn skip_fee_charge_is_an_accepted_no_op() {
+            let (context, last_block_header, _account, requests) =
+                declare_deploy_invoke_batch().await;
+
+            let input = |simulation_flags| EstimateFeeInput {
+                request: requests.clone(),
+                simulation_flags,
+                block_id: BlockId::Number(last_block_header.number),
+                forecast: false,
+            };
+
+            let without = estimate_fee(context.clone(), input(vec![])).await.unwrap();
+            let with = estimate_fee(context, input(vec![SimulationFlag::SkipFeeCharge]))
+                .await
+                .unwrap();
+
+            // `estimate_fee` never charges the fee to begin with, so accepting
+            // `SKIP_FEE_CHARGE` must leave the estimate untouched rather than
+            // silently taking a different path.
+            assert_eq!(without, with);
+        }
+
+        #[test_log::test(tokio::test)]
+        async fn forecast_projects_the_known_window_onto_the_estimate() {
+            let (context, last_block_header, _account, requests) =
+                declare_deploy_invoke_batch().await;
+
+            let base_input = |forecast| EstimateFeeInput {
+                request: requests.clone(),
+                simulation_flags: vec![],
+                block_id: BlockId::Number(last_block_header.number),
+                forecast,
+            };
+
+            let current = estimate_fee(context.clone(), base_input(false))
+                .await
+                .unwrap();
+            let forecast = estimate_fee(context.clone(), base_input(true))
+                .await
+                .unwrap();
+
+            // Recompute the projection from the same window `forecast_gas_prices`
+            // reads out of storage, anchored on the parent block's recorded price,
+            // and assert the forecast estimate used exactly that price while the
+            // non-forecast one used the parent's price unchanged. This pins both
+            // the anchor (latest, not oldest) and the direction against a known
+            // window rather than merely asserting the two differ.
+            let latest = last_block_header.number;
+            let oldest = latest.get().saturating_sub(FORECAST_WINDOW - 1);
+            let mut db = context.execution_storage.connection().unwrap();
+            let db = db.transaction().unwrap();
+            let parent = db.block_header(latest.into()).unwrap().unwrap();
+            let mut window = vec![parent.transaction_count as u128];
+            for number in (oldest..latest.get()).rev() {
+                let number = pathfinder_common::BlockNumber::new_or_panic(number);
+                match db.block_header(number.into()).unwrap() {
+                    Some(h) => window.push(h.transaction_count as u128),
+                    None => break,
+                }
+            }
+            let expected =
+                forecast_price(parent.eth_l1_gas_price.0, parent.transaction_count as u128, &window);
+
+            assert_eq!(current[0].l1_gas_price, parent.eth_l1_gas_price.0.into());
+            assert_eq!(forecast[0].l1_gas_price, expected.into());
+        }
+
+        #[test_log::test(tokio::test)]
+        async fn per_transaction_returns_every_estimate_when_nothing_reverts() {
+            let (context, last_block_header, _account, requests) =
+                declare_deploy_invoke_batch().await;
+
+            let input = |request| EstimateFeeInput {
+                request,
+                simulation_flags: vec![],
+                block_id: BlockId::Number(last_block_header.number),
+                forecast: false,
+            };
+
+            let batch = estimate_fee(context.clone(), input(requests.clone()))
+                .await
+                .unwrap();
+            let per_transaction = estimate_fee_per_transaction(context, input(requests))
+                .await
+                .unwrap();
+
+            // With no reverts the batch variant returns one `Ok` estimate per
+            // transaction, identical to the all-or-nothing `estimate_fee`.
+            let estimates = per_transaction
+                .into_iter()
+                .map(|entry| entry.expect("every transaction succeeds"))
+                .collect::<Vec<_>>();
+            assert_eq!(estimates, batch);
+        }
+
+        #[test_log::test(tokio::test)]
+        async fn per_transaction_reports_the_failing_step_without_dropping_the_rest() {
+            let (context, last_block_header, _account, mut requests) =
+                declare_deploy_invoke_batch().await;
+
+            // Append a transaction that reverts: an invoke of an entry point the
+            // deployed test contract does not expose.
+            let failing_index = requests.len();
+            requests.push(BroadcastedTransaction::Invoke(
+                BroadcastedInvokeTransaction::V0(BroadcastedInvokeTransactionV0 {
+                    version: TransactionVersion::ONE,
+                    max_fee: Fee::default(),
+                    signature: vec![],
+                    contract_address: contract_address!(
+                        "0x012592426632af714f43ccb05536b6044fc3e897fa55288f658731f93590e7e7"
+                    ),
+                    entry_point_selector: EntryPoint::hashed(b"nonexistent_entry_point"),
+                    calldata: vec![],
+                }),
+            ));
+
+            let input = EstimateFeeInput {
+                request: requests,
+                simulation_flags: vec![],
+                block_id: BlockId::Number(last_block_header.number),
+                forecast: false,
+            };
+            let result = estimate_fee_per_transaction(context, input).await.unwrap();
+
+            // Every preceding transaction is still estimated...
+            assert_eq!(result.len(), failing_index + 1);
+            for entry in &result[..failing_index] {
+                assert!(entry.is_ok(), "expected an estimate, got {entry:?}");
+            }
+            // ...and the reverting one is reported in place rather than aborting
+            // the whole batch.
+            match &result[failing_index] {
+                Err(ExecutionFailure {
+                    transaction_index,
+                    revert_error,
+                }) => {
+                    assert_eq!(*transaction_index, failing_index);
+                    assert!(!revert_error.is_empty());
+                }
+                other => panic!("expected an ExecutionFailure, got {other:?}"),
+            }
+        }
+
+        #[test_log::test(tokio::test)]
+        async fn estimate_invoke_v3_returns_resource_bounds_breakdown() {
+            use pathfinder_common::{ResourceAmount, ResourcePricePerUnit, Tip};
+            use pathfinder_executor::types::PriceUnit;
+
+            use crate::v02::types::request::{
+                BroadcastedInvokeTransactionV3,
+                DataAvailabilityMode,
+                ResourceBound,
+                ResourceBounds,
+            };
+
+            let (context, last_block_header, account, mut requests) =
+                declare_deploy_invoke_batch().await;
+
+            // A V3 invoke of the contract deployed earlier in the batch. V3 fees
+            // are multi-dimensional (per-resource bounds plus a tip) and paid in
+            // STRK, so the estimate must come back denominated in FRI with a
+            // populated L1 gas dimension.
+            let v3_index = requests.len();
+            requests.push(BroadcastedTransaction::Invoke(
+                BroadcastedInvokeTransaction::V3(BroadcastedInvokeTransactionV3 {
+                    version: TransactionVersion::THREE,
+                    signature: vec![],
+                    nonce: transaction_nonce!("0x3"),
+                    resource_bounds: ResourceBounds {
+                        l1_gas: ResourceBound {
+                            max_amount: ResourceAmount(0x10000),
+                            max_price_per_unit: ResourcePricePerUnit(0x100),
+                        },
+                        l2_gas: ResourceBound {
+                            max_amount: ResourceAmount(0),
+                            max_price_per_unit: ResourcePricePerUnit(0),
+                        },
+                    },
+                    tip: Tip(0),
+                    paymaster_data: vec![],
+                    account_deployment_data: vec![],
+                    nonce_data_availability_mode: DataAvailabilityMode::L1,
+                    fee_data_availability_mode: DataAvailabilityMode::L1,
+                    sender_address: account,
+                    calldata: vec![
+                        CallParam(felt!(
+                            "0x012592426632af714f43ccb05536b6044fc3e897fa55288f658731f93590e7e7"
+                        )),
+                        CallParam(EntryPoint::hashed(b"get_data").0),
+                        call_param!("0"),
+                    ],
+                }),
+            ));
+
+            let input = EstimateFeeInput {
+                request: requests,
+                // The V3 transaction is unsigned, so validation is skipped.
+                simulation_flags: vec![SimulationFlag::SkipValidate],
+                block_id: BlockId::Number(last_block_header.number),
+                forecast: false,
+            };
+            let result = estimate_fee(context, input).await.unwrap();
+
+            let v3_estimate = &result[v3_index];
+            // STRK-denominated, with a populated L1 gas dimension...
+            assert_eq!(v3_estimate.unit, PriceUnit::Fri);
+            assert!(v3_estimate.l1_gas_consumed > 0.into());
+            assert!(v3_estimate.l1_gas_price > 0.into());
+            // ...and an overall fee that accounts for every charged resource, so
+            // it is at least the L1 gas contribution to the breakdown.
+            assert!(v3_estimate.overall_fee >= v3_estimate.l1_gas_consumed * v3_estimate.l1_gas_price);
+            assert!(v3_estimate.overall_fee > 0.into());
+        }
     }
 }